@@ -1,16 +1,11 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-// Import BigUint when the feature "large-numbers" is enabled.
-#[cfg(feature = "large-numbers")]
-use num_bigint::BigUint;
-
 use core::fmt;
-// Import the core version of Wrapping when the "std" feature is disabled.
-#[cfg(all(not(feature = "checked-overflow"), not(feature = "std")))]
-use core::num::Wrapping;
-// Import the std version of Wrapping when the "std" feature is enabled.
-#[cfg(all(not(feature = "checked-overflow"), feature = "std"))]
-use std::num::Wrapping;
+use num_traits::{One, Zero};
+#[cfg(feature = "checked-overflow")]
+use num_traits::{CheckedAdd, CheckedMul, CheckedSub};
+#[cfg(not(feature = "checked-overflow"))]
+use num_traits::{WrappingAdd, WrappingMul, WrappingSub};
 
 /// This enum represents the possible errors that could occur during
 /// arithmetic operations in this library.
@@ -36,14 +31,18 @@ impl std::error::Error for ArithmeticError {}
 
 /// The UnsignedInteger trait represents an unsigned integer.
 ///
-/// This trait is used to abstract over different types of unsigned integers,
-/// allowing functions in this library to work with any type of unsigned integer.
-pub trait UnsignedInteger: Clone + core::ops::Add<Output = Self> {
-    /// Returns the zero value for this type of unsigned integer.
-    fn zero() -> Self;
-    /// Returns the one value for this type of unsigned integer.
-    fn one() -> Self;
-
+/// This trait is a thin supertrait over the relevant `num_traits` traits, so any type that
+/// already implements them — the primitive integers, `BigUint`, or a third-party fixed-width
+/// type — works with `Fibonacci` automatically via the blanket impls below.
+pub trait UnsignedInteger:
+    Clone
+    + core::ops::Add<Output = Self>
+    + core::ops::Sub<Output = Self>
+    + core::ops::Mul<Output = Self>
+    + core::ops::Rem<Output = Self>
+    + Zero
+    + One
+{
     /// Adds the given unsigned integer to this one, returning an error if the result would overflow.
     /// This method is only available when the "checked-overflow" feature is enabled.
     #[cfg(feature = "checked-overflow")]
@@ -51,57 +50,89 @@ pub trait UnsignedInteger: Clone + core::ops::Add<Output = Self> {
 
     /// Adds the given unsigned integer to this one, wrapping around at the maximum value of this type.
     /// This method is only available when the "checked-overflow" feature is disabled.
-    #[cfg(all(not(feature = "checked-overflow"), feature = "std"))]
+    #[cfg(not(feature = "checked-overflow"))]
     fn unchecked_add(self, rhs: Self) -> Self;
 
-    /// Adds the given unsigned integer to this one, wrapping around at the maximum value of this type.
-    /// This method is only available when the "checked-overflow" feature is disabled and the "std" feature is also disabled.
-    #[cfg(all(not(feature = "checked-overflow"), not(feature = "std")))]
-    fn unchecked_add(self, rhs: Self) -> Self;
-}
+    /// Multiplies this unsigned integer by the given one, returning an error if the result would overflow.
+    /// This method is only available when the "checked-overflow" feature is enabled.
+    #[cfg(feature = "checked-overflow")]
+    fn safe_mul(self, rhs: Self) -> Result<Self, ArithmeticError>;
 
-// This macro implements the UnsignedInteger trait for the given types.
-macro_rules! impl_unsigned_integer {
-    ($($t:ty)*) => ($(impl UnsignedInteger for $t {
-        fn zero() -> Self { 0 }
-        fn one() -> Self { 1 }
+    /// Multiplies this unsigned integer by the given one, wrapping around at the maximum value of this type.
+    /// This method is only available when the "checked-overflow" feature is disabled.
+    #[cfg(not(feature = "checked-overflow"))]
+    fn unchecked_mul(self, rhs: Self) -> Self;
 
-        // Rust's inherent checked_add
-        #[cfg(feature = "checked-overflow")]
-        fn safe_add(self, rhs: Self) -> Result<Self, ArithmeticError> {
-            self.checked_add(rhs).ok_or(ArithmeticError::Overflow)
-        }
+    /// Subtracts the given unsigned integer from this one, returning an error if the result would underflow.
+    /// This method is only available when the "checked-overflow" feature is enabled.
+    #[cfg(feature = "checked-overflow")]
+    fn safe_sub(self, rhs: Self) -> Result<Self, ArithmeticError>;
 
-        // Adds the given unsigned integer to this one, wrapping around at the maximum value of this type.
-        // This method is only available when the "checked-overflow" feature is disabled and the "std" feature is enabled.
-        #[cfg(all(not(feature = "checked-overflow"), feature = "std"))]
-        fn unchecked_add(self, rhs: Self) -> Self {
-            Wrapping(self).0.wrapping_add(Wrapping(rhs).0)
-        }
-    })*)
+    /// Subtracts the given unsigned integer from this one, wrapping around at the minimum value of this type.
+    /// This method is only available when the "checked-overflow" feature is disabled.
+    #[cfg(not(feature = "checked-overflow"))]
+    fn unchecked_sub(self, rhs: Self) -> Self;
 }
 
-// Implement UnsignedInteger for the unsigned primitive integer types.
-impl_unsigned_integer! { u8 u16 u32 u64 u128 }
-
-// Implement UnsignedInteger for BigUint when the "large-numbers" feature is enabled.
-#[cfg(feature = "large-numbers")]
-impl UnsignedInteger for BigUint {
-    fn zero() -> Self {
-        BigUint::from(0u32)
+// Blanket impl for the "checked-overflow" feature: any type with the num-traits checked
+// arithmetic ops gets UnsignedInteger for free, including BigUint (whose additions and
+// multiplications always succeed; a subtraction still fails if it would go below zero).
+#[cfg(feature = "checked-overflow")]
+impl<T> UnsignedInteger for T
+where
+    T: Clone
+        + core::ops::Add<Output = T>
+        + core::ops::Sub<Output = T>
+        + core::ops::Mul<Output = T>
+        + core::ops::Rem<Output = T>
+        + Zero
+        + One
+        + CheckedAdd
+        + CheckedMul
+        + CheckedSub,
+{
+    fn safe_add(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        self.checked_add(&rhs).ok_or(ArithmeticError::Overflow)
     }
-    fn one() -> Self {
-        BigUint::from(1u32)
+
+    fn safe_mul(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        self.checked_mul(&rhs).ok_or(ArithmeticError::Overflow)
     }
 
-    #[cfg(feature = "checked-overflow")]
-    fn safe_add(self, rhs: Self) -> Result<Self, ArithmeticError> {
-        Ok(self + rhs) // BigUint never overflows
+    fn safe_sub(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        self.checked_sub(&rhs).ok_or(ArithmeticError::Overflow)
     }
+}
 
-    #[cfg(all(not(feature = "checked-overflow"), feature = "std"))]
+// Blanket impl for the default, wrapping-on-overflow behaviour: any type with the num-traits
+// wrapping arithmetic ops gets UnsignedInteger for free.
+//
+// Note: `BigUint` has no fixed width and so never implements the `Wrapping*` traits, which means
+// `Fibonacci<BigUint>` requires the "checked-overflow" feature to be enabled.
+#[cfg(not(feature = "checked-overflow"))]
+impl<T> UnsignedInteger for T
+where
+    T: Clone
+        + core::ops::Add<Output = T>
+        + core::ops::Sub<Output = T>
+        + core::ops::Mul<Output = T>
+        + core::ops::Rem<Output = T>
+        + Zero
+        + One
+        + WrappingAdd
+        + WrappingMul
+        + WrappingSub,
+{
     fn unchecked_add(self, rhs: Self) -> Self {
-        self + rhs // BigUint never overflows
+        self.wrapping_add(&rhs)
+    }
+
+    fn unchecked_mul(self, rhs: Self) -> Self {
+        self.wrapping_mul(&rhs)
+    }
+
+    fn unchecked_sub(self, rhs: Self) -> Self {
+        self.wrapping_sub(&rhs)
     }
 }
 
@@ -121,6 +152,291 @@ impl<T: UnsignedInteger> Fibonacci<T> {
             next: T::one(),
         }
     }
+
+    /// Creates a new Fibonacci-style sequence starting from an arbitrary two-term seed.
+    ///
+    /// For example, `Fibonacci::with_seeds(T::one() + T::one(), T::one())` produces the
+    /// Lucas numbers, which follow the same recurrence as `Fibonacci` but start at `(2, 1)`.
+    pub fn with_seeds(a: T, b: T) -> Fibonacci<T> {
+        Fibonacci {
+            current: a,
+            next: b,
+        }
+    }
+
+    /// Computes F(n) directly in O(log n) arithmetic operations using the fast-doubling
+    /// recurrence, instead of stepping the iterator n times.
+    ///
+    /// Returns `Err(ArithmeticError::Overflow)` if any intermediate step overflows `T`. Note that
+    /// this only ever requires F(n) itself to fit `T`, not F(n+1): unlike `fast_doubling`, which
+    /// hands back the full `(F(k), F(k+1))` pair for recursion, this stops one level short and
+    /// computes only the half of the pair that's actually F(n), so a large F(n+1) that would
+    /// overflow `T` never blocks a request for the smaller F(n).
+    pub fn nth_fast(n: u128) -> Result<T, ArithmeticError> {
+        if n == 0 {
+            return Ok(T::zero());
+        }
+
+        let (a, b) = Self::fast_doubling(n >> 1)?;
+
+        #[cfg(feature = "checked-overflow")]
+        {
+            if n & 1 == 0 {
+                // 2*b - a is always non-negative because b >= a, so the subtraction is safe.
+                let two_b_minus_a = b.clone().safe_add(b.clone())?.safe_sub(a.clone())?;
+                a.safe_mul(two_b_minus_a) // F(2k)
+            } else {
+                a.clone().safe_mul(a)?.safe_add(b.clone().safe_mul(b)?) // F(2k+1)
+            }
+        }
+
+        #[cfg(not(feature = "checked-overflow"))]
+        {
+            if n & 1 == 0 {
+                let two_b_minus_a = b.clone().unchecked_add(b.clone()).unchecked_sub(a.clone());
+                Ok(a.unchecked_mul(two_b_minus_a)) // F(2k)
+            } else {
+                Ok(a.clone().unchecked_mul(a).unchecked_add(b.clone().unchecked_mul(b))) // F(2k+1)
+            }
+        }
+    }
+
+    // Returns the pair (F(k), F(k+1)), halving k at each level of recursion so the whole
+    // sequence reaches n in O(log n) steps rather than O(n).
+    fn fast_doubling(n: u128) -> Result<(T, T), ArithmeticError> {
+        if n == 0 {
+            return Ok((T::zero(), T::one()));
+        }
+
+        let (a, b) = Self::fast_doubling(n >> 1)?;
+
+        #[cfg(feature = "checked-overflow")]
+        let (c, d) = {
+            // 2*b - a is always non-negative because b >= a, so the subtraction is safe.
+            let two_b_minus_a = b.clone().safe_add(b.clone())?.safe_sub(a.clone())?;
+            let c = a.clone().safe_mul(two_b_minus_a)?; // F(2k)
+            let d = a.clone().safe_mul(a.clone())?.safe_add(b.clone().safe_mul(b)?)?; // F(2k+1)
+            (c, d)
+        };
+
+        #[cfg(not(feature = "checked-overflow"))]
+        let (c, d) = {
+            let two_b_minus_a = b.clone().unchecked_add(b.clone()).unchecked_sub(a.clone());
+            let c = a.clone().unchecked_mul(two_b_minus_a); // F(2k)
+            let d = a
+                .clone()
+                .unchecked_mul(a.clone())
+                .unchecked_add(b.clone().unchecked_mul(b)); // F(2k+1)
+            (c, d)
+        };
+
+        if n & 1 == 0 {
+            Ok((c, d))
+        } else {
+            #[cfg(feature = "checked-overflow")]
+            let sum = c.clone().safe_add(d.clone())?;
+            #[cfg(not(feature = "checked-overflow"))]
+            let sum = c.clone().unchecked_add(d.clone());
+
+            Ok((d, sum))
+        }
+    }
+
+    /// Computes F(n) mod `modulus` directly, reducing every intermediate value with the same
+    /// fast-doubling recurrence as [`nth_fast`](Self::nth_fast) so the result stays bounded even
+    /// for astronomically large `n` — particularly useful paired with `BigUint`.
+    ///
+    /// A modulus of one always yields zero. Returns `Err(ArithmeticError::Overflow)` under the
+    /// "checked-overflow" feature if an intermediate product or sum overflows `T` before it can
+    /// be reduced — residues are multiplied together before reduction, so this can happen once
+    /// `modulus` is large enough that the sum of two squared residues no longer fits `T`, and
+    /// means `T` is too narrow for the chosen modulus.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    pub fn nth_mod(n: u128, modulus: T) -> Result<T, ArithmeticError>
+    where
+        T: PartialEq,
+    {
+        assert!(modulus != T::zero(), "nth_mod: modulus must not be zero");
+        Self::fast_doubling_mod(n, &modulus).map(|(value, _)| value)
+    }
+
+    // Returns (F(k) mod m, F(k+1) mod m), reducing every intermediate value modulo m so it
+    // never grows past m, unlike the full-precision fast_doubling above. Arithmetic is routed
+    // through the same safe_*/unchecked_* methods as fast_doubling so an intermediate that
+    // outgrows T surfaces as Err(Overflow) (checked-overflow) or wraps silently, instead of
+    // tripping debug-mode's overflow checks on a raw `+`/`*`.
+    fn fast_doubling_mod(n: u128, modulus: &T) -> Result<(T, T), ArithmeticError> {
+        if n == 0 {
+            return Ok((T::zero() % modulus.clone(), T::one() % modulus.clone()));
+        }
+
+        let (a, b) = Self::fast_doubling_mod(n >> 1, modulus)?;
+
+        #[cfg(feature = "checked-overflow")]
+        let (c, d) = {
+            let two_b = b.clone().safe_add(b.clone())? % modulus.clone();
+            // Adding the modulus before subtracting keeps this in-bounds for an unsigned type:
+            // two_b + modulus is always at least modulus, which is strictly greater than a.
+            let two_b_minus_a =
+                two_b.safe_add(modulus.clone())?.safe_sub(a.clone())? % modulus.clone();
+            let c = a.clone().safe_mul(two_b_minus_a)? % modulus.clone(); // F(2k) mod m
+            let d = a.clone().safe_mul(a.clone())?.safe_add(b.clone().safe_mul(b)?)?
+                % modulus.clone(); // F(2k+1) mod m
+            (c, d)
+        };
+
+        #[cfg(not(feature = "checked-overflow"))]
+        let (c, d) = {
+            let two_b = b.clone().unchecked_add(b.clone()) % modulus.clone();
+            let two_b_minus_a = two_b
+                .unchecked_add(modulus.clone())
+                .unchecked_sub(a.clone())
+                % modulus.clone();
+            let c = a.clone().unchecked_mul(two_b_minus_a) % modulus.clone(); // F(2k) mod m
+            let d = a.clone().unchecked_mul(a.clone()).unchecked_add(b.clone().unchecked_mul(b))
+                % modulus.clone(); // F(2k+1) mod m
+            (c, d)
+        };
+
+        if n & 1 == 0 {
+            Ok((c, d))
+        } else {
+            #[cfg(feature = "checked-overflow")]
+            let sum = c.clone().safe_add(d.clone())? % modulus.clone();
+            #[cfg(not(feature = "checked-overflow"))]
+            let sum = c.clone().unchecked_add(d.clone()) % modulus.clone();
+
+            Ok((d, sum))
+        }
+    }
+
+    /// Finds the Pisano period of the Fibonacci sequence modulo `modulus`: the number of terms
+    /// after which the residue pair `(F(n) mod m, F(n+1) mod m)` returns to its starting value
+    /// `(0, 1)`. Since there are only finitely many such pairs mod `m`, this is guaranteed to
+    /// terminate, and lets callers collapse huge `n` via `F(n) mod m == F(n mod period) mod m`.
+    ///
+    /// Returns `Err(ArithmeticError::Overflow)` under the "checked-overflow" feature if adding two
+    /// residues overflows `T`, which means `T` is too narrow for the chosen modulus.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    pub fn pisano_period(modulus: T) -> Result<u128, ArithmeticError>
+    where
+        T: PartialEq,
+    {
+        assert!(
+            modulus != T::zero(),
+            "pisano_period: modulus must not be zero"
+        );
+
+        let zero = T::zero() % modulus.clone();
+        let one = T::one() % modulus.clone();
+
+        let mut a = zero.clone();
+        let mut b = one.clone();
+        let mut period: u128 = 0;
+
+        loop {
+            #[cfg(feature = "checked-overflow")]
+            let next = a.clone().safe_add(b.clone())? % modulus.clone();
+            #[cfg(not(feature = "checked-overflow"))]
+            let next = a.clone().unchecked_add(b.clone()) % modulus.clone();
+
+            a = b;
+            b = next;
+            period += 1;
+
+            if a == zero && b == one {
+                return Ok(period);
+            }
+        }
+    }
+
+    /// Advances the sequence in place by `n` positions, jumping directly to the target state
+    /// via the fast-doubling state transition instead of stepping (and cloning) one term at a
+    /// time.
+    ///
+    /// Under the "checked-overflow" feature, if the jump itself would overflow, this falls back
+    /// to single steps so the sequence ends up in exactly the state it would have reached by
+    /// stepping one term at a time, stopping exactly where that walk would have stopped.
+    pub fn advance_by(&mut self, n: u128) {
+        #[cfg(feature = "checked-overflow")]
+        {
+            if let Some((current, next)) = Self::jump(&self.current, &self.next, n) {
+                self.current = current;
+                self.next = next;
+                return;
+            }
+
+            // The batched jump overflowed somewhere inside it; fall back to single steps,
+            // mirroring Iterator::next's own update, so overflow is hit at the same position.
+            for _ in 0..n {
+                let stepped = match self.current.clone().safe_add(self.next.clone()) {
+                    Ok(value) => value,
+                    Err(ArithmeticError::Overflow) => break,
+                };
+                self.current = self.next.clone();
+                self.next = stepped;
+            }
+        }
+
+        #[cfg(not(feature = "checked-overflow"))]
+        {
+            let (current, next) = Self::jump(&self.current, &self.next, n);
+            self.current = current;
+            self.next = next;
+        }
+    }
+
+    // Given an arbitrary state (x_k, x_{k+1}) of the recurrence, returns (x_{k+n}, x_{k+n+1})
+    // using the identities x_{k+n} = F(n-1)*x_k + F(n)*x_{k+1} and
+    // x_{k+n+1} = F(n)*x_k + F(n+1)*x_{k+1}, reusing fast_doubling to get F(n) and F(n+1).
+    #[cfg(feature = "checked-overflow")]
+    fn jump(current: &T, next: &T, n: u128) -> Option<(T, T)> {
+        if n == 0 {
+            return Some((current.clone(), next.clone()));
+        }
+
+        let (fn_, fn_plus_1) = Self::fast_doubling(n).ok()?;
+        let fn_minus_1 = fn_plus_1.clone().safe_sub(fn_.clone()).ok()?;
+
+        let new_current = fn_minus_1
+            .safe_mul(current.clone())
+            .ok()?
+            .safe_add(fn_.clone().safe_mul(next.clone()).ok()?)
+            .ok()?;
+        let new_next = fn_
+            .safe_mul(current.clone())
+            .ok()?
+            .safe_add(fn_plus_1.safe_mul(next.clone()).ok()?)
+            .ok()?;
+
+        Some((new_current, new_next))
+    }
+
+    #[cfg(not(feature = "checked-overflow"))]
+    fn jump(current: &T, next: &T, n: u128) -> (T, T) {
+        if n == 0 {
+            return (current.clone(), next.clone());
+        }
+
+        // fast_doubling never returns Err without "checked-overflow" enabled.
+        let (fn_, fn_plus_1) = Self::fast_doubling(n).expect("fast_doubling cannot overflow here");
+        let fn_minus_1 = fn_plus_1.clone().unchecked_sub(fn_.clone());
+
+        let new_current = fn_minus_1
+            .unchecked_mul(current.clone())
+            .unchecked_add(fn_.clone().unchecked_mul(next.clone()));
+        let new_next = fn_
+            .unchecked_mul(current.clone())
+            .unchecked_add(fn_plus_1.unchecked_mul(next.clone()));
+
+        (new_current, new_next)
+    }
 }
 
 // Implement Default for Fibonacci, which just calls Fibonacci::new.
@@ -151,32 +467,111 @@ impl<T: UnsignedInteger> Iterator for Fibonacci<T> {
             return Some(current);
         }
 
-        // Without "checked-overflow" and with "std", use wrapping addition.
-        #[cfg(all(not(feature = "checked-overflow"), feature = "std"))]
+        // Without "checked-overflow", wrap around at the maximum value of T.
+        #[cfg(not(feature = "checked-overflow"))]
         {
+            let next = self.current.clone().unchecked_add(self.next.clone());
+
             let current = self.current.clone();
             self.current = self.next.clone();
-            self.next = self.current.clone().unchecked_add(self.next.clone());
+            self.next = next;
 
             return Some(current);
         }
+    }
 
-        // Without "checked-overflow" and without "std", use regular addition.
-        #[cfg(all(not(feature = "checked-overflow"), not(feature = "std")))]
-        {
-            let current = self.current.clone();
-            self.current = self.next.clone();
-            self.next = self.current + self.next;
+    // Jumps directly to the nth element via advance_by instead of stepping (and cloning) n
+    // times, so advancing far ahead stays cheap even for BigUint.
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.advance_by(n as u128);
+        self.next()
+    }
+}
 
-            return Some(current);
+/// A generalized constant-coefficient linear recurrence of order `K`: each new term is the
+/// weighted sum of the previous `K` terms,
+/// `x_n = c_0 * x_{n-1} + c_1 * x_{n-2} + ... + c_{K-1} * x_{n-K}`.
+///
+/// This generalizes `Fibonacci` (order 2, coefficients `[1, 1]`) to sequences such as the
+/// tribonacci numbers (order 3, coefficients `[1, 1, 1]`) or any other linear recurrence with
+/// fixed coefficients.
+// These fields are only read by the `Iterator` impl below, so without the "iterator" feature
+// they'd otherwise trigger a dead_code warning.
+#[cfg_attr(not(feature = "iterator"), allow(dead_code))]
+pub struct LinearRecurrence<T: UnsignedInteger, const K: usize> {
+    // Ring buffer of the last K terms; `head` is the index of the oldest one.
+    terms: [T; K],
+    coefficients: [T; K],
+    head: usize,
+}
+
+impl<T: UnsignedInteger, const K: usize> LinearRecurrence<T, K> {
+    /// Creates a new linear recurrence from `K` seed terms (oldest first) and the `K`
+    /// coefficients (newest-term first, i.e. `coefficients[0]` multiplies `x_{n-1}`).
+    pub fn new(seeds: [T; K], coefficients: [T; K]) -> Self {
+        LinearRecurrence {
+            terms: seeds,
+            coefficients,
+            head: 0,
         }
     }
 }
 
+// Implement Iterator for LinearRecurrence, allowing the sequence to be generated lazily.
+#[cfg(feature = "iterator")]
+impl<T: UnsignedInteger, const K: usize> Iterator for LinearRecurrence<T, K> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.terms[self.head].clone();
+
+        // With the "checked-overflow" feature, return None on overflow.
+        #[cfg(feature = "checked-overflow")]
+        let new_term = {
+            let mut acc = T::zero();
+            for j in 0..K {
+                let idx = (self.head + K - 1 - j) % K;
+                let term = match self.coefficients[j].clone().safe_mul(self.terms[idx].clone()) {
+                    Ok(term) => term,
+                    Err(ArithmeticError::Overflow) => return None,
+                };
+                acc = match acc.safe_add(term) {
+                    Ok(acc) => acc,
+                    Err(ArithmeticError::Overflow) => return None,
+                };
+            }
+            acc
+        };
+
+        // Without "checked-overflow", wrap around at the maximum value of T.
+        #[cfg(not(feature = "checked-overflow"))]
+        let new_term = {
+            let mut acc = T::zero();
+            for j in 0..K {
+                let idx = (self.head + K - 1 - j) % K;
+                let term = self.coefficients[j].clone().unchecked_mul(self.terms[idx].clone());
+                acc = acc.unchecked_add(term);
+            }
+            acc
+        };
+
+        self.terms[self.head] = new_term;
+        self.head = (self.head + 1) % K;
+
+        Some(value)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    // BigUint has no fixed width and so never implements the Wrapping* traits, which means
+    // BigUint only implements UnsignedInteger under "checked-overflow"; only the
+    // tests name BigUint directly, so the import lives here rather than at the crate root.
+    #[cfg(feature = "large-numbers")]
+    use num_bigint::BigUint;
+
     // This function tests the Fibonacci sequence for a given unsigned integer type.
     // It is only available when the "std" feature is enabled.
     #[cfg(feature = "std")]
@@ -230,7 +625,9 @@ mod tests {
         test_fibonacci::<u128>();
     }
 
-    #[cfg(all(feature = "std", feature = "large-numbers"))]
+    // BigUint has no Wrapping* impls, so it only implements UnsignedInteger (and so only
+    // works with Fibonacci) under "checked-overflow" — see the blanket impls' comments above.
+    #[cfg(all(feature = "std", feature = "large-numbers", feature = "checked-overflow"))]
     #[test]
     fn test_fibonacci_big_uint() {
         test_fibonacci::<BigUint>();
@@ -281,7 +678,28 @@ mod tests {
         assert_eq!(fib.next(), Some(5));
     }
 
+    // Test that nth_fast agrees with stepping the iterator for the first several indices.
+    #[cfg(feature = "iterator")]
+    #[test]
+    fn test_fibonacci_nth_fast() {
+        let mut fib = Fibonacci::<u32>::new();
+        for n in 0..20u128 {
+            assert_eq!(Fibonacci::<u32>::nth_fast(n), Ok(fib.next().unwrap()));
+        }
+    }
+
+    // Test that nth_fast reports overflow only once F(n) itself no longer fits u8, even though
+    // the u8 iterator already stops before yielding F(12) (it needs F(14) for that step).
+    #[cfg(feature = "checked-overflow")]
+    #[test]
+    fn test_fibonacci_nth_fast_overflow() {
+        // F(13) = 233, F(14) = 377 overflows u8.
+        assert_eq!(Fibonacci::<u8>::nth_fast(13), Ok(233));
+        assert_eq!(Fibonacci::<u8>::nth_fast(14), Err(ArithmeticError::Overflow));
+    }
+
     // Test the handling of overflow in the Fibonacci sequence.
+    #[cfg(all(feature = "checked-overflow", feature = "iterator"))]
     #[test]
     fn test_fibonacci_overflow() {
         let mut fib = Fibonacci::<u8>::new();
@@ -291,4 +709,136 @@ mod tests {
         // After overflowing the u8 type, the sequence should return None.
         assert_eq!(fib.next(), None);
     }
+
+    // Test the with_seeds function of the Fibonacci struct.
+    #[test]
+    fn test_fibonacci_with_seeds() {
+        let fib: Fibonacci<u8> = Fibonacci::with_seeds(2, 1);
+        assert_eq!(fib.current, 2);
+        assert_eq!(fib.next, 1);
+    }
+
+    // Test that with_seeds(2, 1) reproduces the Lucas numbers.
+    #[cfg(feature = "iterator")]
+    #[test]
+    fn test_fibonacci_lucas_numbers() {
+        let mut lucas = Fibonacci::<u32>::with_seeds(2, 1);
+        assert_eq!(lucas.next(), Some(2));
+        assert_eq!(lucas.next(), Some(1));
+        assert_eq!(lucas.next(), Some(3));
+        assert_eq!(lucas.next(), Some(4));
+        assert_eq!(lucas.next(), Some(7));
+        assert_eq!(lucas.next(), Some(11));
+    }
+
+    // Test that a K=3 LinearRecurrence with coefficients [1, 1, 1] reproduces the tribonacci
+    // sequence starting from the seeds (0, 1, 1).
+    #[cfg(feature = "iterator")]
+    #[test]
+    fn test_linear_recurrence_tribonacci() {
+        let mut tribonacci = LinearRecurrence::<u32, 3>::new([0, 1, 1], [1, 1, 1]);
+        assert_eq!(tribonacci.next(), Some(0));
+        assert_eq!(tribonacci.next(), Some(1));
+        assert_eq!(tribonacci.next(), Some(1));
+        assert_eq!(tribonacci.next(), Some(2));
+        assert_eq!(tribonacci.next(), Some(4));
+        assert_eq!(tribonacci.next(), Some(7));
+        assert_eq!(tribonacci.next(), Some(13));
+    }
+
+    // Test that nth_mod agrees with reducing nth_fast's full-precision result modulo m.
+    #[test]
+    fn test_fibonacci_nth_mod() {
+        let modulus = 1000u32;
+        for n in 0..30u128 {
+            let expected = Fibonacci::<u32>::nth_fast(n).unwrap() % modulus;
+            assert_eq!(Fibonacci::<u32>::nth_mod(n, modulus), Ok(expected));
+        }
+    }
+
+    // A modulus of one should always yield zero.
+    #[test]
+    fn test_fibonacci_nth_mod_modulus_one() {
+        for n in 0..5u128 {
+            assert_eq!(Fibonacci::<u32>::nth_mod(n, 1), Ok(0));
+        }
+    }
+
+    // Test the Pisano period against known values (OEIS A001175).
+    #[test]
+    fn test_fibonacci_pisano_period() {
+        assert_eq!(Fibonacci::<u32>::pisano_period(2), Ok(3));
+        assert_eq!(Fibonacci::<u32>::pisano_period(3), Ok(8));
+        assert_eq!(Fibonacci::<u32>::pisano_period(10), Ok(60));
+    }
+
+    // Test that the Pisano period lets nth_mod collapse an astronomically large n.
+    #[test]
+    fn test_fibonacci_nth_mod_pisano_period_shortcut() {
+        let modulus = 10u32;
+        let period = Fibonacci::<u32>::pisano_period(modulus).unwrap();
+        let n = 1_000_000u128;
+        assert_eq!(
+            Fibonacci::<u32>::nth_mod(n, modulus),
+            Fibonacci::<u32>::nth_mod(n % period, modulus)
+        );
+    }
+
+    // Test that advance_by repositions the cursor exactly like stepping one term at a time.
+    #[cfg(feature = "iterator")]
+    #[test]
+    fn test_fibonacci_advance_by() {
+        let mut advanced = Fibonacci::<u32>::new();
+        advanced.advance_by(10);
+
+        let mut stepped = Fibonacci::<u32>::new();
+        for _ in 0..10 {
+            stepped.next();
+        }
+
+        assert_eq!(advanced.next(), stepped.next());
+    }
+
+    // Test that the overridden nth agrees with the default step-by-step implementation.
+    #[cfg(feature = "iterator")]
+    #[test]
+    fn test_fibonacci_nth() {
+        let mut stepped = Fibonacci::<u32>::new();
+        for _ in 0..7 {
+            stepped.next();
+        }
+        let expected = stepped.next();
+
+        let mut fib = Fibonacci::<u32>::new();
+        assert_eq!(fib.nth(7), expected);
+    }
+
+    // Test that advance_by ends up in exactly the same (overflowed) state as stepping one term
+    // at a time when the batched jump itself would overflow.
+    #[cfg(all(feature = "checked-overflow", feature = "iterator"))]
+    #[test]
+    fn test_fibonacci_advance_by_overflow_matches_stepping() {
+        let mut advanced = Fibonacci::<u8>::new();
+        advanced.advance_by(300);
+
+        let mut stepped = Fibonacci::<u8>::new();
+        for _ in 0..300 {
+            if stepped.next().is_none() {
+                break;
+            }
+        }
+
+        assert_eq!(advanced.next(), stepped.next());
+    }
+
+    // Test that a K=2 LinearRecurrence with coefficients [1, 1] behaves like Fibonacci.
+    #[cfg(feature = "iterator")]
+    #[test]
+    fn test_linear_recurrence_matches_fibonacci() {
+        let mut fib = Fibonacci::<u32>::new();
+        let mut recurrence = LinearRecurrence::<u32, 2>::new([0, 1], [1, 1]);
+        for _ in 0..20 {
+            assert_eq!(recurrence.next(), fib.next());
+        }
+    }
 }